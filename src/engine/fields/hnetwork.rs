@@ -1,6 +1,6 @@
 use crate::engine::fields::field::Field;
 use hashbrown::HashMap;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::HashSet;
 use std::fmt::Display;
 use std::hash::Hash;
@@ -62,6 +62,23 @@ impl<L: Clone + Hash + Display> HEdge<L> {
     }
 }
 
+/// A single, invertible mutation recorded into an `HNetwork`'s undo journal once
+/// [`HNetwork::begin_transaction`] has been called.
+#[derive(Clone)]
+pub enum HNetOp<O: Clone, L: Clone + Hash + Display> {
+    AddNode(O),
+    /// the node that was removed, its original id, and every hyper-edge it was incident
+    /// to. Undoing this restores the node at its original id via `restore_node`, so the
+    /// id doesn't collide with whatever node was assigned it in the meantime.
+    RemoveObject(O, u32, Vec<HEdge<L>>),
+    /// the nodes, the options just applied, and the hyper-edge they replaced, if any
+    /// (`add_edge` updates an existing hyper-edge over the same nodes in place instead of
+    /// duplicating it). Undoing restores the replaced edge, or removes the edge entirely
+    /// if there was none.
+    AddEdge(Vec<O>, EdgeOptions<L>, Option<HEdge<L>>),
+    RemoveEdge(HEdge<L>),
+}
+
 impl<L> PartialEq for HEdge<L>
 where
     L: Clone + Hash + Display,
@@ -86,6 +103,19 @@ pub struct HNetwork<O: Hash + Eq + Clone + Display, L: Clone + Hash + Display> {
     pub id2nodes: RefCell<HashMap<u32, O>>,
     /// Read state to manage Nodes using ids
     pub rid2nodes: RefCell<HashMap<u32, O>>,
+    /// Write state: secondary index from the canonical (sorted) node-id set of a hyper-edge
+    /// to the hyper-edge itself, for O(1) existence/lookup instead of scanning `edges`
+    edge_index: RefCell<HashMap<Box<[u32]>, HEdge<L>>>,
+    /// Read state counterpart of `edge_index`
+    redge_index: RefCell<HashMap<Box<[u32]>, HEdge<L>>>,
+    /// Undo journal, populated with the inverse of every mutation once transactions are enabled
+    journal: RefCell<Vec<HNetOp<O, L>>>,
+    /// Operations undone so far, replayable through `redo`
+    redo_stack: RefCell<Vec<HNetOp<O, L>>>,
+    /// Becomes `true` after `begin_transaction` is called
+    journaling_enabled: Cell<bool>,
+    /// Set while `undo`/`redo` are replaying a mutation, so it isn't journaled again
+    replaying: Cell<bool>,
 }
 
 impl<O: Hash + Eq + Clone + Display, L: Clone + Hash + Display> HNetwork<O, L> {
@@ -97,14 +127,134 @@ impl<O: Hash + Eq + Clone + Display, L: Clone + Hash + Display> HNetwork<O, L> {
             nodes2id: RefCell::new(HashMap::new()),
             id2nodes: RefCell::new(HashMap::new()),
             rid2nodes: RefCell::new(HashMap::new()),
+            edge_index: RefCell::new(HashMap::new()),
+            redge_index: RefCell::new(HashMap::new()),
+            journal: RefCell::new(Vec::new()),
+            redo_stack: RefCell::new(Vec::new()),
+            journaling_enabled: Cell::new(false),
+            replaying: Cell::new(false),
+        }
+    }
+
+    /// Enable the undo journal: from this point on, `add_node`, `remove_object`, `add_edge`
+    /// and `remove_edge` are recorded and can be rolled back with `undo`/`redo`.
+    pub fn begin_transaction(&self) {
+        self.journaling_enabled.set(true);
+    }
+
+    /// Record `op` into the undo journal, unless journaling is disabled or this call is
+    /// itself part of an `undo`/`redo` replay.
+    fn record(&self, op: HNetOp<O, L>) {
+        if self.journaling_enabled.get() && !self.replaying.get() {
+            self.journal.borrow_mut().push(op);
+            self.redo_stack.borrow_mut().clear();
+        }
+    }
+
+    /// Undo the last recorded mutation, replaying its inverse.
+    pub fn undo(&self) {
+        let op = match self.journal.borrow_mut().pop() {
+            Some(op) => op,
+            None => return,
+        };
+
+        self.replaying.set(true);
+        match &op {
+            HNetOp::AddNode(o) => {
+                self.remove_object(o.clone());
+            }
+            HNetOp::RemoveObject(o, uid, edges) => {
+                self.restore_node(o.clone(), *uid);
+                for hedge in edges {
+                    self.restore_edge(hedge);
+                }
+            }
+            HNetOp::AddEdge(nodes, _, previous) => match previous {
+                Some(hedge) => {
+                    // drop the updated edge first so the restored one doesn't sit beside it
+                    self.remove_edge_with_hedge(hedge);
+                    self.restore_edge(hedge);
+                }
+                None => {
+                    self.remove_edge(nodes);
+                }
+            },
+            HNetOp::RemoveEdge(hedge) => {
+                self.restore_edge(hedge);
+            }
+        }
+        self.replaying.set(false);
+
+        self.redo_stack.borrow_mut().push(op);
+    }
+
+    /// Redo the last operation undone by `undo`.
+    pub fn redo(&self) {
+        let op = match self.redo_stack.borrow_mut().pop() {
+            Some(op) => op,
+            None => return,
+        };
+
+        self.replaying.set(true);
+        match &op {
+            HNetOp::AddNode(o) => {
+                self.add_node(o.clone());
+            }
+            HNetOp::RemoveObject(o, _, _) => {
+                self.remove_object(o.clone());
+            }
+            HNetOp::AddEdge(nodes, edge_options, _) => {
+                self.add_edge(nodes, edge_options.clone());
+            }
+            HNetOp::RemoveEdge(hedge) => {
+                self.remove_edge_with_hedge(hedge);
+            }
+        }
+        self.replaying.set(false);
+
+        self.journal.borrow_mut().push(op);
+    }
+
+    /// Re-insert a previously removed hyper-edge verbatim, using the node ids it already
+    /// carries (they are still valid as long as the incident nodes were not removed too).
+    fn restore_edge(&self, hedge: &HEdge<L>) {
+        let ids: Vec<u32> = hedge.nodes.iter().copied().collect();
+
+        self.edge_index
+            .borrow_mut()
+            .insert(Self::canonical_key(&ids), hedge.clone());
+
+        let mut edges = self.edges.borrow_mut();
+        for id in &ids {
+            edges.entry(*id).or_insert_with(Vec::new).push(hedge.clone());
         }
     }
 
+    /// Re-insert a previously removed node at its original id, bypassing the fresh-id
+    /// assignment `add_node` does, so undoing a `remove_object` can't collide with a node
+    /// added in the meantime.
+    fn restore_node(&self, u: O, uid: u32) {
+        self.nodes2id.borrow_mut().insert(u.clone(), uid);
+        self.id2nodes.borrow_mut().insert(uid, u);
+        self.edges.borrow_mut().entry(uid).or_insert_with(Vec::new);
+    }
+
+    /// Canonical form of a hyper-edge's node-id set: sorted and de-duplicated, so two
+    /// hyper-edges over the same nodes always map to the same index key regardless of
+    /// the order the nodes were passed in.
+    fn canonical_key(ids: &[u32]) -> Box<[u32]> {
+        let mut sorted = ids.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        sorted.into_boxed_slice()
+    }
+
     // fn default() -> Self {
     //     Self::new()
     // }
 
-    /// Add a new hyper-edge
+    /// Add a new hyper-edge. If a hyper-edge over the same set of nodes already exists, its
+    /// label/weight are updated in place instead of appending a duplicate.
     pub fn add_edge(&self, nodes: &[O], edge_options: EdgeOptions<L>) -> bool {
         if nodes.is_empty() {
             return false;
@@ -121,19 +271,39 @@ impl<O: Hash + Eq + Clone + Display, L: Clone + Hash + Display> HNetwork<O, L> {
         }
         let ids = ids.as_slice();
 
+        let new_edge = HEdge::new(ids, edge_options.clone());
+        let key = Self::canonical_key(ids);
+
+        let mut edge_index = self.edge_index.borrow_mut();
+        let previous = edge_index.get(&key).cloned();
+        let already_existed = previous.is_some();
+        edge_index.insert(key, new_edge.clone());
+
         let mut edges = self.edges.borrow_mut();
 
         for id in ids {
             match edges.get_mut(id) {
                 Some(uedges) => {
-                    uedges.push(HEdge::new(ids, edge_options.clone()));
+                    if already_existed {
+                        if let Some(existing) = uedges.iter_mut().find(|e| **e == new_edge) {
+                            *existing = new_edge.clone();
+                        } else {
+                            uedges.push(new_edge.clone());
+                        }
+                    } else {
+                        uedges.push(new_edge.clone());
+                    }
                 }
                 None => {
-                    let vec = vec![HEdge::new(ids, edge_options.clone())];
-                    edges.insert(*id, vec);
+                    edges.insert(*id, vec![new_edge.clone()]);
                 }
             }
         }
+        drop(edges);
+        drop(edge_index);
+        drop(nodes2id);
+
+        self.record(HNetOp::AddEdge(nodes.to_vec(), edge_options, previous));
 
         true
     }
@@ -144,7 +314,7 @@ impl<O: Hash + Eq + Clone + Display, L: Clone + Hash + Display> HNetwork<O, L> {
         let mut id2nodes = self.id2nodes.borrow_mut();
         let uid = nodes2id.len() as u32;
         nodes2id.insert(u.clone(), uid);
-        id2nodes.insert(uid, u);
+        id2nodes.insert(uid, u.clone());
 
         let mut edges = self.edges.borrow_mut();
         match edges.get(&uid) {
@@ -154,9 +324,15 @@ impl<O: Hash + Eq + Clone + Display, L: Clone + Hash + Display> HNetwork<O, L> {
                 edges.insert(uid, vec);
             }
         }
+        drop(edges);
+        drop(id2nodes);
+        drop(nodes2id);
+
+        self.record(HNetOp::AddNode(u));
     }
 
-    /// Get an hyper-edge from a list of nodes
+    /// Get an hyper-edge from a list of nodes. Constant-time: backed by the canonical
+    /// adjacency index instead of scanning the per-node edge lists.
     pub fn get_edge(&self, nodes: &[O]) -> Option<HEdge<L>> {
         if nodes.is_empty() {
             return None;
@@ -172,19 +348,8 @@ impl<O: Hash + Eq + Clone + Display, L: Clone + Hash + Display> HNetwork<O, L> {
             }
         }
 
-        let edges = self.redges.borrow();
-        match edges.get(&ids[0]) {
-            Some(uedges) => {
-                let edge: HEdge<L> = HEdge::new(ids.as_slice(), EdgeOptions::Simple);
-                for e in uedges {
-                    if *e == edge {
-                        return Some(e.clone());
-                    }
-                }
-                None
-            }
-            None => None,
-        }
+        let key = Self::canonical_key(&ids);
+        self.redge_index.borrow().get(&key).cloned()
     }
 
     /// Get all edges of a node
@@ -207,6 +372,7 @@ impl<O: Hash + Eq + Clone + Display, L: Clone + Hash + Display> HNetwork<O, L> {
     pub fn remove_all_edges(&self) {
         let mut edges = self.edges.borrow_mut();
         edges.clear();
+        self.edge_index.borrow_mut().clear();
     }
 
     /// Remove a specific edge using a list of nodes
@@ -228,9 +394,9 @@ impl<O: Hash + Eq + Clone + Display, L: Clone + Hash + Display> HNetwork<O, L> {
         let mut all_edges = self.edges.borrow_mut();
         let to_remove: HEdge<L> = HEdge::new(ids.as_slice(), EdgeOptions::Simple);
 
-        for id in ids {
+        for id in &ids {
             let edges = all_edges
-                .get_mut(&id)
+                .get_mut(id)
                 .expect("error on get_mut of all_edges");
 
             let index = match edges.iter().position(|entry| *entry == to_remove) {
@@ -243,6 +409,16 @@ impl<O: Hash + Eq + Clone + Display, L: Clone + Hash + Display> HNetwork<O, L> {
             }
         }
 
+        self.edge_index
+            .borrow_mut()
+            .remove(&Self::canonical_key(&ids));
+        drop(all_edges);
+        drop(nodes2id);
+
+        if let Some(ref hedge) = removed {
+            self.record(HNetOp::RemoveEdge(hedge.clone()));
+        }
+
         removed
     }
 
@@ -266,6 +442,11 @@ impl<O: Hash + Eq + Clone + Display, L: Clone + Hash + Display> HNetwork<O, L> {
             }
         }
 
+        let ids: Vec<u32> = to_remove.nodes.iter().copied().collect();
+        self.edge_index
+            .borrow_mut()
+            .remove(&Self::canonical_key(&ids));
+
         removed
     }
 
@@ -280,10 +461,9 @@ impl<O: Hash + Eq + Clone + Display, L: Clone + Hash + Display> HNetwork<O, L> {
             };
         }
 
-        if let Some(to_remove) = self.get_edges(u.clone()) {
-            for hedge in to_remove {
-                self.remove_edge_with_hedge(&hedge);
-            }
+        let removed_edges = self.get_edges(u.clone()).unwrap_or_default();
+        for hedge in &removed_edges {
+            self.remove_edge_with_hedge(hedge);
         }
 
         let mut id2nodes = self.id2nodes.borrow_mut();
@@ -291,6 +471,10 @@ impl<O: Hash + Eq + Clone + Display, L: Clone + Hash + Display> HNetwork<O, L> {
 
         id2nodes.remove(&uid);
         nodes2id.remove(&u);
+        drop(id2nodes);
+        drop(nodes2id);
+
+        self.record(HNetOp::RemoveObject(u, uid, removed_edges));
         true
     }
 
@@ -306,6 +490,308 @@ impl<O: Hash + Eq + Clone + Display, L: Clone + Hash + Display> HNetwork<O, L> {
             *value = u
         }
     }
+
+    /// Neighbor ids of a node, hyper-graph style: the union of every node-id appearing
+    /// in any hyper-edge incident to `uid`, excluding `uid` itself.
+    fn neighbor_ids(&self, uid: u32, redges: &HashMap<u32, Vec<HEdge<L>>>) -> HashSet<u32> {
+        let mut neighbors = HashSet::new();
+        if let Some(incident) = redges.get(&uid) {
+            for hedge in incident {
+                for &n in hedge.nodes.iter() {
+                    if n != uid {
+                        neighbors.insert(n);
+                    }
+                }
+            }
+        }
+        neighbors
+    }
+
+    /// Breadth-first search from `start`, returning every reachable node mapped to its BFS depth.
+    /// `start` itself is returned at depth `0`.
+    pub fn breadth_first_search(&self, start: O) -> HashMap<O, u32> {
+        let mut result = HashMap::new();
+
+        let nodes2id = self.nodes2id.borrow();
+        let start_id = match nodes2id.get(&start) {
+            Some(id) => *id,
+            None => return result,
+        };
+
+        let redges = self.redges.borrow();
+        let rid2nodes = self.rid2nodes.borrow();
+
+        let mut visited = HashSet::new();
+        let mut frontier = std::collections::VecDeque::new();
+        visited.insert(start_id);
+        frontier.push_back((start_id, 0u32));
+
+        while let Some((uid, depth)) = frontier.pop_front() {
+            if let Some(o) = rid2nodes.get(&uid) {
+                result.insert(o.clone(), depth);
+            }
+            for neighbor in self.neighbor_ids(uid, &redges) {
+                if visited.insert(neighbor) {
+                    frontier.push_back((neighbor, depth + 1));
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Partitions every node of the network into its connected components, each returned as
+    /// the list of nodes reachable from one another through a chain of hyper-edges.
+    pub fn connected_components(&self) -> Vec<Vec<O>> {
+        let mut components = Vec::new();
+        let rid2nodes = self.rid2nodes.borrow();
+        let redges = self.redges.borrow();
+
+        let mut visited: HashSet<u32> = HashSet::new();
+
+        for &uid in rid2nodes.keys() {
+            if visited.contains(&uid) {
+                continue;
+            }
+
+            let mut component = Vec::new();
+            let mut frontier = std::collections::VecDeque::new();
+            visited.insert(uid);
+            frontier.push_back(uid);
+
+            while let Some(current) = frontier.pop_front() {
+                if let Some(o) = rid2nodes.get(&current) {
+                    component.push(o.clone());
+                }
+                for neighbor in self.neighbor_ids(current, &redges) {
+                    if visited.insert(neighbor) {
+                        frontier.push_back(neighbor);
+                    }
+                }
+            }
+
+            components.push(component);
+        }
+
+        components
+    }
+
+    /// Dijkstra's algorithm over hyper-edges, weighted by `HEdge::weight` (defaulting to `1.0`).
+    /// Relaxing a node `u` through an incident hyper-edge `e` makes every other member of
+    /// `e.nodes` reachable at `dist[u] + e.weight.unwrap_or(1.0)`. Negative weights are not
+    /// supported: in debug builds they trip an assertion, in release they are skipped.
+    /// Returns the distance from `src` to every reachable node.
+    pub fn distances_from(&self, src: O) -> HashMap<O, f32> {
+        let src_id = {
+            let nodes2id = self.nodes2id.borrow();
+            match nodes2id.get(&src) {
+                Some(id) => *id,
+                None => return HashMap::new(),
+            }
+        };
+
+        let (dist, _) = self.dijkstra(src_id);
+
+        let rid2nodes = self.rid2nodes.borrow();
+        dist.into_iter()
+            .filter_map(|(id, d)| rid2nodes.get(&id).cloned().map(|o| (o, d)))
+            .collect()
+    }
+
+    /// Weighted shortest path between `src` and `dst`, returning its total cost and the
+    /// sequence of nodes crossed (`src` and `dst` included). See [`HNetwork::distances_from`]
+    /// for how edge weights are interpreted.
+    pub fn shortest_path(&self, src: O, dst: O) -> Option<(f32, Vec<O>)> {
+        let (src_id, dst_id) = {
+            let nodes2id = self.nodes2id.borrow();
+            (*nodes2id.get(&src)?, *nodes2id.get(&dst)?)
+        };
+
+        let (dist, prev) = self.dijkstra(src_id);
+        let total_cost = *dist.get(&dst_id)?;
+
+        let mut path_ids = vec![dst_id];
+        let mut current = dst_id;
+        while current != src_id {
+            current = *prev.get(&current)?;
+            path_ids.push(current);
+        }
+        path_ids.reverse();
+
+        let rid2nodes = self.rid2nodes.borrow();
+        let path = path_ids
+            .into_iter()
+            .map(|id| rid2nodes.get(&id).cloned())
+            .collect::<Option<Vec<O>>>()?;
+
+        Some((total_cost, path))
+    }
+
+    /// Shared Dijkstra implementation backing `shortest_path`/`distances_from`. The priority
+    /// queue is a 4-ary heap rather than a binary `BinaryHeap`: on the dense hypergraphs this
+    /// type targets, nodes can have a very high degree, and a wider branching factor cuts down
+    /// the number of sift operations triggered per relaxation. Since `f32` isn't `Ord`, distances
+    /// are queued by their bit pattern, which preserves ordering for finite non-negative floats.
+    fn dijkstra(&self, src_id: u32) -> (HashMap<u32, f32>, HashMap<u32, u32>) {
+        let redges = self.redges.borrow();
+
+        let mut dist: HashMap<u32, f32> = HashMap::new();
+        let mut prev: HashMap<u32, u32> = HashMap::new();
+        let mut queue = QuaternaryHeap::new();
+
+        dist.insert(src_id, 0.);
+        queue.push(0f32.to_bits(), src_id);
+
+        while let Some((dist_bits, uid)) = queue.pop() {
+            let d = f32::from_bits(dist_bits);
+            // lazy deletion: skip entries made stale by a later, cheaper push for the same node
+            if matches!(dist.get(&uid), Some(&best) if d > best) {
+                continue;
+            }
+
+            let incident = match redges.get(&uid) {
+                Some(incident) => incident,
+                None => continue,
+            };
+
+            for hedge in incident {
+                let weight = hedge.weight.unwrap_or(1.0);
+                debug_assert!(
+                    weight >= 0.0,
+                    "HNetwork shortest-path queries do not support negative hyper-edge weights"
+                );
+                if weight < 0.0 {
+                    continue;
+                }
+
+                for &n in hedge.nodes.iter() {
+                    if n == uid {
+                        continue;
+                    }
+                    let candidate = d + weight;
+                    let improves = match dist.get(&n) {
+                        Some(&existing) => candidate < existing,
+                        None => true,
+                    };
+                    if improves {
+                        dist.insert(n, candidate);
+                        prev.insert(n, uid);
+                        queue.push(candidate.to_bits(), n);
+                    }
+                }
+            }
+        }
+
+        (dist, prev)
+    }
+
+    /// Build the spatial-neighborhood hypergraph of a set of 2D points: the Delaunay
+    /// triangulation of `points` is computed and, for every site, one hyper-edge is added
+    /// containing that site together with every Delaunay-adjacent neighbor -- i.e. the
+    /// edge-adjacent neighbors of its Voronoi cell. Alongside the network, each site's Voronoi
+    /// cell centroid and area are returned (the dual polygon formed by the circumcenters of its
+    /// incident Delaunay triangles), so e.g. a companion pheromone grid can weight deposition by
+    /// cell area. Cells on the convex hull are geometrically unbounded; the centroid/area
+    /// reported for them only cover the bounded portion enclosed by their incident circumcenters.
+    pub fn from_voronoi(points: &[(O, f64, f64)]) -> (HNetwork<O, L>, HashMap<O, voronoi::Cell>) {
+        let network = HNetwork::new();
+        for (o, _, _) in points {
+            network.add_node(o.clone());
+        }
+
+        let coords: Vec<(f64, f64)> = points.iter().map(|&(_, x, y)| (x, y)).collect();
+        let triangles = voronoi::delaunay_triangles(&coords);
+
+        let mut neighbors: Vec<HashSet<usize>> = vec![HashSet::new(); points.len()];
+        let mut incident_circumcenters: Vec<Vec<(f64, f64)>> = vec![Vec::new(); points.len()];
+        for tri in &triangles {
+            for &(u, v) in &[(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+                neighbors[u].insert(v);
+                neighbors[v].insert(u);
+            }
+            // collinear/duplicate sites produce a degenerate (zero-area) triangle with no
+            // well-defined circumcenter; skip it rather than let the cell geometry go NaN
+            if let Some(center) =
+                voronoi::circumcenter(coords[tri[0]], coords[tri[1]], coords[tri[2]])
+            {
+                for &i in tri {
+                    incident_circumcenters[i].push(center);
+                }
+            }
+        }
+
+        let mut cells = HashMap::new();
+        for (i, (o, x, y)) in points.iter().enumerate() {
+            let cell = voronoi::bounded_cell((*x, *y), &incident_circumcenters[i]);
+            cells.insert(o.clone(), cell);
+
+            if !neighbors[i].is_empty() {
+                let mut members = vec![o.clone()];
+                members.extend(neighbors[i].iter().map(|&n| points[n].0.clone()));
+                network.add_edge(&members, EdgeOptions::Simple);
+            }
+        }
+
+        (network, cells)
+    }
+}
+
+/// Minimal 4-ary min-heap keyed by `(f32-bit-pattern, node id)`, used by `HNetwork::dijkstra`.
+/// Relies on lazy deletion rather than decrease-key: callers just push a new, cheaper entry
+/// for a node and skip stale entries when they are popped.
+struct QuaternaryHeap {
+    entries: Vec<(u32, u32)>,
+}
+
+impl QuaternaryHeap {
+    fn new() -> Self {
+        QuaternaryHeap { entries: Vec::new() }
+    }
+
+    fn push(&mut self, key: u32, node: u32) {
+        self.entries.push((key, node));
+        let mut i = self.entries.len() - 1;
+        while i > 0 {
+            let parent = (i - 1) / 4;
+            if self.entries[i] < self.entries[parent] {
+                self.entries.swap(i, parent);
+                i = parent;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn pop(&mut self) -> Option<(u32, u32)> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let last = self.entries.len() - 1;
+        self.entries.swap(0, last);
+        let top = self.entries.pop();
+
+        let len = self.entries.len();
+        let mut i = 0;
+        loop {
+            let first_child = i * 4 + 1;
+            if first_child >= len {
+                break;
+            }
+            let mut smallest = i;
+            for c in first_child..(first_child + 4).min(len) {
+                if self.entries[c] < self.entries[smallest] {
+                    smallest = c;
+                }
+            }
+            if smallest == i {
+                break;
+            }
+            self.entries.swap(i, smallest);
+            i = smallest;
+        }
+
+        top
+    }
 }
 
 impl<O: Hash + Eq + Clone + Display, L: Clone + Hash + Display> Default for HNetwork<O, L> {
@@ -324,6 +810,11 @@ impl<O: Hash + Eq + Clone + Display, L: Clone + Hash + Display> Field for HNetwo
         let mut rid2nodes = self.rid2nodes.borrow_mut();
 
         *rid2nodes = id2nodes.clone();
+
+        let edge_index = self.edge_index.borrow();
+        let mut redge_index = self.redge_index.borrow_mut();
+
+        *redge_index = edge_index.clone();
     }
 
     fn lazy_update(&mut self) {
@@ -335,5 +826,307 @@ impl<O: Hash + Eq + Clone + Display, L: Clone + Hash + Display> Field for HNetwo
         let mut rid2nodes = self.rid2nodes.borrow_mut();
 
         *rid2nodes = id2nodes.clone();
+
+        let edge_index = self.edge_index.borrow();
+        let mut redge_index = self.redge_index.borrow_mut();
+
+        *redge_index = edge_index.clone();
+    }
+}
+
+/// Random hypergraph constructors, analogous to petgraph's generator helpers: each one
+/// populates a fresh `HNetwork` directly instead of requiring callers to wire up thousands
+/// of `add_edge` calls by hand.
+pub mod generators {
+    use super::{EdgeOptions, HNetwork};
+    use rand::Rng;
+    use std::fmt::Display;
+    use std::hash::Hash;
+
+    /// Erdos-Renyi-style random hypergraph: every node in `nodes` is added, then `num_edges`
+    /// hyper-edges are created, each over `cardinality` distinct nodes drawn uniformly at
+    /// random. `edge_options` is invoked once per generated hyper-edge, so labels/weights can
+    /// vary (pass e.g. `|| EdgeOptions::Simple` for unlabeled, unweighted edges).
+    pub fn erdos_renyi_hypergraph<O, L, R>(
+        nodes: &[O],
+        num_edges: usize,
+        cardinality: usize,
+        rng: &mut R,
+        mut edge_options: impl FnMut() -> EdgeOptions<L>,
+    ) -> HNetwork<O, L>
+    where
+        O: Hash + Eq + Clone + Display,
+        L: Clone + Hash + Display,
+        R: Rng,
+    {
+        let network = HNetwork::new();
+        for node in nodes {
+            network.add_node(node.clone());
+        }
+
+        let cardinality = cardinality.min(nodes.len());
+        for _ in 0..num_edges {
+            let members = sample_distinct(nodes, cardinality, rng);
+            network.add_edge(&members, edge_options());
+        }
+
+        network
+    }
+
+    /// Preferential-attachment hypergraph: a node's probability of being picked for a new
+    /// hyper-edge is proportional to its current incident hyper-edge count (every node starts
+    /// with a weight of `1` so isolated nodes can still be picked), generalizing the classic
+    /// Barabasi-Albert degree bias to hyperedges. Sampling uses a running cumulative-weight
+    /// table searched with binary search.
+    pub fn preferential_attachment_hypergraph<O, L, R>(
+        nodes: &[O],
+        num_edges: usize,
+        cardinality: usize,
+        rng: &mut R,
+        mut edge_options: impl FnMut() -> EdgeOptions<L>,
+    ) -> HNetwork<O, L>
+    where
+        O: Hash + Eq + Clone + Display,
+        L: Clone + Hash + Display,
+        R: Rng,
+    {
+        let network = HNetwork::new();
+        for node in nodes {
+            network.add_node(node.clone());
+        }
+
+        let mut weight: Vec<u64> = vec![1; nodes.len()];
+        let cardinality = cardinality.min(nodes.len());
+
+        for _ in 0..num_edges {
+            let mut chosen = Vec::with_capacity(cardinality);
+            while chosen.len() < cardinality {
+                let idx = weighted_pick(&weight, rng);
+                if !chosen.contains(&idx) {
+                    chosen.push(idx);
+                }
+            }
+
+            let members: Vec<O> = chosen.iter().map(|&i| nodes[i].clone()).collect();
+            network.add_edge(&members, edge_options());
+
+            for &i in &chosen {
+                weight[i] += 1;
+            }
+        }
+
+        network
+    }
+
+    /// Sample `count` distinct elements of `pool` uniformly at random, without replacement.
+    fn sample_distinct<O: Clone, R: Rng>(pool: &[O], count: usize, rng: &mut R) -> Vec<O> {
+        let mut remaining: Vec<usize> = (0..pool.len()).collect();
+        let mut chosen = Vec::with_capacity(count);
+        for _ in 0..count.min(pool.len()) {
+            let pick = rng.gen_range(0..remaining.len());
+            chosen.push(pool[remaining.remove(pick)].clone());
+        }
+        chosen
+    }
+
+    /// Pick an index with probability proportional to `weights`, via a running cumulative-weight
+    /// table sampled by binary search.
+    fn weighted_pick<R: Rng>(weights: &[u64], rng: &mut R) -> usize {
+        let mut cumulative = Vec::with_capacity(weights.len());
+        let mut acc = 0u64;
+        for &w in weights {
+            acc += w;
+            cumulative.push(acc);
+        }
+
+        let point = rng.gen_range(0..acc);
+        match cumulative.binary_search(&point) {
+            // `point` landing exactly on `cumulative[i]` is the first point of element
+            // `i + 1`'s half-open bucket `[cumulative[i], cumulative[i + 1])`, not `i`'s
+            Ok(i) => (i + 1).min(weights.len() - 1),
+            Err(i) => i,
+        }
+    }
+}
+
+/// Delaunay triangulation and its Voronoi dual, used by `HNetwork::from_voronoi`.
+pub mod voronoi {
+    use std::collections::HashMap as StdHashMap;
+
+    /// A site's Voronoi cell: centroid and area of the polygon formed by the circumcenters of
+    /// its incident Delaunay triangles.
+    #[derive(Clone, Copy, Debug)]
+    pub struct Cell {
+        pub centroid: (f64, f64),
+        pub area: f64,
+    }
+
+    /// Delaunay-adjacent site-index pairs (`i < j`) for a point set, via the Bowyer-Watson
+    /// incremental algorithm. Exposed standalone so callers who only need the topology (not the
+    /// dual Voronoi cells) can skip building an `HNetwork`.
+    pub fn delaunay_edges(points: &[(f64, f64)]) -> Vec<(usize, usize)> {
+        let triangles = delaunay_triangles(points);
+        let mut edges: std::collections::HashSet<(usize, usize)> = std::collections::HashSet::new();
+        for tri in &triangles {
+            for &(u, v) in &[(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+                edges.insert(if u < v { (u, v) } else { (v, u) });
+            }
+        }
+        edges.into_iter().collect()
+    }
+
+    /// Bowyer-Watson incremental Delaunay triangulation: start from a super-triangle enclosing
+    /// every point, insert points one at a time by removing every triangle whose circumcircle
+    /// contains the new point and re-triangulating the resulting polygonal hole, then discard
+    /// any triangle still touching a super-triangle vertex. Triangles are returned with vertices
+    /// wound counter-clockwise, indexed into `points`.
+    pub(super) fn delaunay_triangles(points: &[(f64, f64)]) -> Vec<[usize; 3]> {
+        let n = points.len();
+        if n < 3 {
+            return Vec::new();
+        }
+
+        let (mut min_x, mut max_x) = (f64::INFINITY, f64::NEG_INFINITY);
+        let (mut min_y, mut max_y) = (f64::INFINITY, f64::NEG_INFINITY);
+        for &(x, y) in points {
+            min_x = min_x.min(x);
+            max_x = max_x.max(x);
+            min_y = min_y.min(y);
+            max_y = max_y.max(y);
+        }
+        let delta = (max_x - min_x).max(max_y - min_y).max(1.0) * 20.0;
+        let (mid_x, mid_y) = ((min_x + max_x) / 2.0, (min_y + max_y) / 2.0);
+
+        let mut coords: Vec<(f64, f64)> = points.to_vec();
+        let s0 = coords.len();
+        coords.push((mid_x - delta, mid_y - delta));
+        coords.push((mid_x + delta, mid_y - delta));
+        coords.push((mid_x, mid_y + delta));
+
+        let mut triangles: Vec<[usize; 3]> = vec![[s0, s0 + 1, s0 + 2]];
+
+        for i in 0..n {
+            let p = coords[i];
+
+            let bad: Vec<usize> = triangles
+                .iter()
+                .enumerate()
+                .filter(|(_, tri)| in_circumcircle(coords[tri[0]], coords[tri[1]], coords[tri[2]], p))
+                .map(|(idx, _)| idx)
+                .collect();
+
+            let mut edge_count: StdHashMap<(usize, usize), u32> = StdHashMap::new();
+            for &idx in &bad {
+                let tri = triangles[idx];
+                for &(u, v) in &[(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+                    let key = if u < v { (u, v) } else { (v, u) };
+                    *edge_count.entry(key).or_insert(0) += 1;
+                }
+            }
+
+            let mut boundary: Vec<(usize, usize)> = Vec::new();
+            for &idx in &bad {
+                let tri = triangles[idx];
+                for &(u, v) in &[(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+                    let key = if u < v { (u, v) } else { (v, u) };
+                    if edge_count[&key] == 1 {
+                        boundary.push((u, v));
+                    }
+                }
+            }
+
+            for &idx in bad.iter().rev() {
+                triangles.remove(idx);
+            }
+
+            for (u, v) in boundary {
+                let mut new_tri = [u, v, i];
+                if signed_area2(coords[u], coords[v], coords[i]) < 0.0 {
+                    new_tri.swap(0, 1);
+                }
+                triangles.push(new_tri);
+            }
+        }
+
+        triangles.retain(|tri| tri.iter().all(|&idx| idx < n));
+        triangles
+    }
+
+    /// Twice the signed area of triangle `a, b, c`: positive when wound counter-clockwise.
+    fn signed_area2(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> f64 {
+        (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+    }
+
+    /// Whether `p` lies inside the circumcircle of counter-clockwise-wound triangle `a, b, c`,
+    /// via the standard in-circle determinant test.
+    fn in_circumcircle(a: (f64, f64), b: (f64, f64), c: (f64, f64), p: (f64, f64)) -> bool {
+        let (ax, ay) = (a.0 - p.0, a.1 - p.1);
+        let (bx, by) = (b.0 - p.0, b.1 - p.1);
+        let (cx, cy) = (c.0 - p.0, c.1 - p.1);
+
+        let det = (ax * ax + ay * ay) * (bx * cy - cx * by)
+            - (bx * bx + by * by) * (ax * cy - cx * ay)
+            + (cx * cx + cy * cy) * (ax * by - bx * ay);
+        det > 0.0
+    }
+
+    /// Circumcenter of triangle `a, b, c`, or `None` if the three points are collinear (or
+    /// coincident), since then no finite circumcenter exists.
+    pub(super) fn circumcenter(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> Option<(f64, f64)> {
+        let d = 2.0 * (a.0 * (b.1 - c.1) + b.0 * (c.1 - a.1) + c.0 * (a.1 - b.1));
+        if d.abs() < f64::EPSILON {
+            return None;
+        }
+        let a2 = a.0 * a.0 + a.1 * a.1;
+        let b2 = b.0 * b.0 + b.1 * b.1;
+        let c2 = c.0 * c.0 + c.1 * c.1;
+
+        let ux = (a2 * (b.1 - c.1) + b2 * (c.1 - a.1) + c2 * (a.1 - b.1)) / d;
+        let uy = (a2 * (c.0 - b.0) + b2 * (a.0 - c.0) + c2 * (b.0 - a.0)) / d;
+        Some((ux, uy))
+    }
+
+    /// Centroid/area of the polygon formed by a site's incident circumcenters, ordered around
+    /// the site by angle before applying the shoelace formula.
+    pub(super) fn bounded_cell(site: (f64, f64), circumcenters: &[(f64, f64)]) -> Cell {
+        let mut polygon = circumcenters.to_vec();
+        polygon.sort_by(|p, q| {
+            let angle_p = (p.1 - site.1).atan2(p.0 - site.0);
+            let angle_q = (q.1 - site.1).atan2(q.0 - site.0);
+            // degenerate circumcenters (NaN, from a near-collinear triangle that slipped
+            // through) sort as equal rather than panicking the comparison
+            angle_p.partial_cmp(&angle_q).unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        if polygon.len() < 3 {
+            return Cell {
+                centroid: site,
+                area: 0.0,
+            };
+        }
+
+        let mut area2 = 0.0;
+        let mut cx = 0.0;
+        let mut cy = 0.0;
+        for i in 0..polygon.len() {
+            let (x0, y0) = polygon[i];
+            let (x1, y1) = polygon[(i + 1) % polygon.len()];
+            let cross = x0 * y1 - x1 * y0;
+            area2 += cross;
+            cx += (x0 + x1) * cross;
+            cy += (y0 + y1) * cross;
+        }
+
+        if area2.abs() < f64::EPSILON {
+            return Cell {
+                centroid: site,
+                area: 0.0,
+            };
+        }
+
+        Cell {
+            centroid: (cx / (3.0 * area2), cy / (3.0 * area2)),
+            area: area2.abs() / 2.0,
+        }
     }
 }
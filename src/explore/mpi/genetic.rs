@@ -35,16 +35,236 @@ macro_rules! extend_dataframe_explore {
     };
 }
 
+/// Criteria that the root rank evaluates once per generation, in addition to
+/// `desired_fitness` and `generation_num`, to decide whether the genetic
+/// exploration should stop early.
+pub enum GAStopCriteria {
+    /// Stop if the overall best fitness has not improved for `generations` consecutive generations
+    NoImprovement { generations: u32 },
+    /// Stop once the least-squares slope of the last `window` best-fitness values
+    /// stays below `epsilon` for `patience` consecutive generations
+    FitnessSlope {
+        window: usize,
+        epsilon: f64,
+        patience: u32,
+    },
+    /// Stop as soon as any of the given criteria triggers
+    Or(Vec<GAStopCriteria>),
+}
+
+/// Tracks, generation after generation, the state needed to evaluate a `GAStopCriteria`:
+/// the best-fitness history (for the slope window) and the no-improvement/under-epsilon counters.
+pub struct GAStopTracker {
+    best_fitness_history: Vec<f32>,
+    generations_without_improvement: u32,
+    last_overall_best: f32,
+    slope_below_epsilon_count: u32,
+}
+
+impl GAStopTracker {
+    pub fn new() -> GAStopTracker {
+        GAStopTracker {
+            best_fitness_history: Vec::new(),
+            generations_without_improvement: 0,
+            last_overall_best: 0.,
+            slope_below_epsilon_count: 0,
+        }
+    }
+
+    /// Feed the overall best fitness found up to the generation that just completed and decide,
+    /// according to `criteria`, whether the exploration should stop.
+    pub fn should_stop(&mut self, overall_best_fitness: f32, criteria: &GAStopCriteria) -> bool {
+        self.best_fitness_history.push(overall_best_fitness);
+
+        if overall_best_fitness > self.last_overall_best {
+            self.last_overall_best = overall_best_fitness;
+            self.generations_without_improvement = 0;
+        } else {
+            self.generations_without_improvement += 1;
+        }
+
+        self.evaluate(criteria)
+    }
+
+    fn evaluate(&mut self, criteria: &GAStopCriteria) -> bool {
+        match criteria {
+            GAStopCriteria::NoImprovement { generations } => {
+                self.generations_without_improvement >= *generations
+            }
+            GAStopCriteria::FitnessSlope {
+                window,
+                epsilon,
+                patience,
+            } => {
+                let slope = self.fitness_slope(*window);
+                match slope {
+                    Some(slope) if slope.abs() < *epsilon => {
+                        self.slope_below_epsilon_count += 1;
+                    }
+                    _ => {
+                        self.slope_below_epsilon_count = 0;
+                    }
+                }
+                self.slope_below_epsilon_count >= *patience
+            }
+            GAStopCriteria::Or(sub_criteria) => {
+                // evaluate every sub-criterion so their internal counters stay up to date
+                sub_criteria
+                    .iter()
+                    .fold(false, |stop, c| self.evaluate(c) || stop)
+            }
+        }
+    }
+
+    /// Least-squares slope of `(generation, best_fitness)` over the last `window` generations.
+    fn fitness_slope(&self, window: usize) -> Option<f64> {
+        if self.best_fitness_history.len() < window {
+            return None;
+        }
+
+        let values = &self.best_fitness_history[self.best_fitness_history.len() - window..];
+        let w = window as f64;
+
+        let mut sum_x = 0.;
+        let mut sum_y = 0.;
+        let mut sum_xy = 0.;
+        let mut sum_xx = 0.;
+
+        for (x, &y) in values.iter().enumerate() {
+            let x = x as f64;
+            let y = y as f64;
+            sum_x += x;
+            sum_y += y;
+            sum_xy += x * y;
+            sum_xx += x * x;
+        }
+
+        let denom = w * sum_xx - sum_x * sum_x;
+        if denom == 0. {
+            return None;
+        }
+
+        Some((w * sum_xy - sum_x * sum_y) / denom)
+    }
+}
+
+impl Default for GAStopTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Context handed to the adaptive `mutation_rate`/`selection_rate` functions each generation,
+/// so the user's genetic operators can read how the run is progressing instead of relying
+/// on a fixed constant.
+pub struct GAAdaptiveContext {
+    pub generation: u32,
+    /// best fitness found in this generation
+    pub best_fitness: f32,
+    /// difference between this generation's best fitness and the running average of recent bests;
+    /// close to zero (or negative) means progress has stalled
+    pub progress: f32,
+    /// fraction of distinct serialized individuals in the current population, in `[0, 1]`;
+    /// close to zero means the population has collapsed onto a handful of individuals
+    pub diversity: f32,
+}
+
+/// Built-in selection operators for `explore_ga_distributed_mpi!`/`explore_ga_distributed_mpi_island!`,
+/// matching the `(population: &mut Vec<(String, f32)>, rate: f64)` signature expected by `$selection`
+/// so they can be passed directly in place of a user-defined closure.
+pub mod selection {
+    use rand::Rng;
+
+    /// k-way tournament selection: repeatedly draws `k` random individuals and keeps the
+    /// fittest, until the mating pool matches the original population size. `rate` is read
+    /// as the tournament size relative to the population (clamped between 2 and `population.len()`).
+    pub fn tournament(population: &mut Vec<(String, f32)>, rate: f64) {
+        let pop_size = population.len();
+        if pop_size == 0 {
+            return;
+        }
+        let k = ((pop_size as f64 * rate).round() as usize).clamp(2, pop_size);
+        let mut rng = rand::thread_rng();
+
+        let mut mating_pool = Vec::with_capacity(pop_size);
+        for _ in 0..pop_size {
+            let mut best = &population[rng.gen_range(0..pop_size)];
+            for _ in 1..k {
+                let candidate = &population[rng.gen_range(0..pop_size)];
+                if candidate.1 > best.1 {
+                    best = candidate;
+                }
+            }
+            mating_pool.push(best.clone());
+        }
+        *population = mating_pool;
+    }
+
+    /// Fitness-proportionate roulette-wheel selection: builds the cumulative sum of
+    /// normalized fitness and samples a uniform point per slot, binary-searching the winner.
+    /// `rate` is unused, kept only to match the shared `$selection` signature.
+    pub fn roulette_wheel(population: &mut Vec<(String, f32)>, _rate: f64) {
+        let pop_size = population.len();
+        if pop_size == 0 {
+            return;
+        }
+
+        let total_fitness: f32 = population.iter().map(|(_, f)| f).sum();
+        let mut cumulative = Vec::with_capacity(pop_size);
+        let mut acc = 0.;
+        for (_, fitness) in population.iter() {
+            acc += if total_fitness > 0. {
+                fitness / total_fitness
+            } else {
+                1. / pop_size as f32
+            };
+            cumulative.push(acc);
+        }
+
+        let mut rng = rand::thread_rng();
+        let mut mating_pool = Vec::with_capacity(pop_size);
+        for _ in 0..pop_size {
+            let point: f32 = rng.gen_range(0.0..1.0);
+            let winner = match cumulative.binary_search_by(|c| c.partial_cmp(&point).unwrap()) {
+                Ok(i) => i,
+                Err(i) => i.min(pop_size - 1),
+            };
+            mating_pool.push(population[winner].clone());
+        }
+        *population = mating_pool;
+    }
+
+    /// Truncation selection: keeps only the top `rate` fraction of the population by fitness.
+    pub fn truncation(population: &mut Vec<(String, f32)>, rate: f64) {
+        population.sort_by(|(_, fit_a), (_, fit_b)| fit_b.partial_cmp(fit_a).unwrap());
+        let keep = ((population.len() as f64 * rate).ceil() as usize)
+            .max(1)
+            .min(population.len());
+        population.truncate(keep);
+    }
+}
+
 // macro to perform distribued model exploration using a genetic algorithm based on MPI
 // an individual is the state of the simulation to compute
 // init_population: function that creates the population, must return an array of individual
 // fitness: function that computes the fitness value, takes a single individual and the schedule, must return an f32
-// mutation: function that perform the mutation, takes a single individual as parameter
+// mutation: function that perform the mutation, takes a single individual and a mutation rate as parameter
 // crossover: function that creates the population, takes the entire population as parameter
 // state: state of the simulation representing an individual
 // desired_fitness: desired fitness value
 // generation_num: max number of generations to compute
 // step: number of steps of the single simulation
+// stop_criteria: `GAStopCriteria` evaluated on the root rank each generation,
+//                on top of desired_fitness/generation_num
+// elitism_count: number of top individuals copied unchanged into the next generation
+// mutation_rate: fn(&GAAdaptiveContext) -> f64, read by the `mutation` operator each generation
+// selection_rate: fn(&GAAdaptiveContext) -> f64, read by the `selection` operator each generation
+// with the "global_cache" feature enabled, the fitness of each serialized individual is
+// memoized per rank so repeated (e.g. elitist) individuals are not re-simulated; only
+// enable it when `$fitness` is deterministic
+// log_path: `Option<&str>`, path of a per-generation CSV progress log; `None` disables logging
+// log_population: if true (and log_path is set), also append the full serialized population
+//                 of each generation to "<log_path>.population"
 #[macro_export]
 macro_rules! explore_ga_distributed_mpi {
     (
@@ -57,6 +277,12 @@ macro_rules! explore_ga_distributed_mpi {
         $desired_fitness: expr,
         $generation_num: expr,
         $step: expr,
+        $stop_criteria: expr,
+        $elitism_count: expr,
+        $mutation_rate: expr,
+        $selection_rate: expr,
+        $log_path: expr,
+        $log_population: expr,
     ) => {{
 
         // MPI initialization
@@ -74,9 +300,18 @@ macro_rules! explore_ga_distributed_mpi {
         let mut generation: u32 = 0;
         let mut best_fitness = 0.;
         let mut best_generation = 0;
+        // sliding window of recent generations' best fitness, used to measure progress
+        let mut recent_best_fitness: std::collections::VecDeque<f32> =
+            std::collections::VecDeque::with_capacity(6);
         let mut my_pop_size: usize = 0;
         let mut population: Vec<String> = Vec::new();
         let mut population_size = 0;
+
+        // memoizes the fitness already computed for a given serialized individual, so
+        // elitists and re-generated individuals are not re-simulated; only useful when
+        // `$fitness` is deterministic, so it is gated behind the "global_cache" feature
+        #[cfg(feature = "global_cache")]
+        let mut fitness_cache: std::collections::HashMap<String, f32> = std::collections::HashMap::new();
         
         //definition of a dataframe called BufferGA
         build_dataframe_explore!(BufferGA,
@@ -104,6 +339,9 @@ macro_rules! explore_ga_distributed_mpi {
         //becomes true when the algorithm get desider fitness
         let mut flag = false;
 
+        // tracks no-improvement / fitness-slope state for $stop_criteria, evaluated on the root
+        let mut stop_tracker = GAStopTracker::new();
+
         // initialization of best individual placeholder
         let mut best_individual: Option<BufferGA> = None;
 
@@ -135,12 +373,28 @@ macro_rules! explore_ga_distributed_mpi {
                 break;
             }
 
+            if world.rank() == root_rank
+                && generation > 0
+                && stop_tracker.should_stop(best_fitness, &$stop_criteria)
+            {
+                println!("Stop criteria met on generation {}, exiting...", generation);
+                flag = true;
+            }
+            root_process.broadcast_into(&mut flag);
+            if flag {
+                break;
+            }
+
             generation += 1;
 
             if world.rank() == root_rank {
                 println!("Running Generation {}...", generation);
             }
-            
+
+            // each generation re-splits the (possibly resized) population from scratch,
+            // so last generation's parameters must not linger
+            population_params.clear();
+
             let mut samples_count: Vec<Count> = Vec::new();
 
             // only the root process split the workload among the processes
@@ -205,15 +459,17 @@ macro_rules! explore_ga_distributed_mpi {
                 // every other processor receive the parameter
                 let (my_population_size, _) = world.any_process().receive::<usize>();
                 my_pop_size = my_population_size;
-                // let (param, _) = world.any_process().receive::<DynBufferMut>();
-                // let my_param = param;
 
-                // for i in 0..my_param.len(){
-                //     population_params.push(my_param[i]);
-                // }
+                // receive this rank's share of serialized individuals, sent one at a time
+                // by the root in the loop above
+                for _ in 0..my_pop_size {
+                    let (bytes, _) = root_process.receive_vec::<u8>();
+                    let individual =
+                        String::from_utf8(bytes).expect("invalid individual encoding");
+                    population_params.push(individual);
+                }
             }
-        }
-  /*           
+
             // let mut my_population: Vec<String>  = Vec::new();
 
             // //init local sub-population
@@ -226,6 +482,24 @@ macro_rules! explore_ga_distributed_mpi {
             let mut my_results: Vec<BufferGA> = Vec::new();
 
             for individual_params in population_params.iter_mut() {
+                // with the "global_cache" feature on, skip the simulation entirely when this
+                // exact individual was already evaluated in a previous generation
+                #[cfg(feature = "global_cache")]
+                if let Some(&cached_fitness) = fitness_cache.get(individual_params) {
+                    let result = BufferGA::new(
+                        generation,
+                        local_index,
+                        cached_fitness,
+                        individual_params.clone(),
+                    );
+                    my_results.push(result);
+                    if cached_fitness >= $desired_fitness {
+                        flag = true;
+                    }
+                    local_index += 1;
+                    continue;
+                }
+
                 // initialize the state
                 let mut individual = <$state>::new_with_parameters(&individual_params);
                 let mut schedule: Schedule = Schedule::new();
@@ -240,6 +514,8 @@ macro_rules! explore_ga_distributed_mpi {
                 }
                 // compute the fitness value
                 let fitness = $fitness(&mut individual, schedule);
+                #[cfg(feature = "global_cache")]
+                fitness_cache.insert(individual_params.clone(), fitness);
                 // send the result of each iteration to the master
                 // $(
                 //     let mut $vec_p_name: [$vec_p_type; $vec_len] = [0; $vec_len];
@@ -321,6 +597,57 @@ macro_rules! explore_ga_distributed_mpi {
                     }
                 }
 
+                // per-generation statistics, computed on the fitness values just gathered
+                let gen_fitness: Vec<f32> = partial_results.iter().map(|r| r.fitness).collect();
+                let n = gen_fitness.len() as f32;
+                let mean_fitness = gen_fitness.iter().sum::<f32>() / n;
+                let std_fitness =
+                    (gen_fitness.iter().map(|f| (f - mean_fitness).powi(2)).sum::<f32>() / n).sqrt();
+                let meeting_desired = gen_fitness.iter().filter(|&&f| f >= $desired_fitness).count();
+
+                // opt-in structured CSV log, one row per generation, for offline analysis/resume
+                if let Some(log_path) = $log_path {
+                    use std::io::Write;
+                    let is_new_log = !std::path::Path::new(log_path).exists();
+                    let mut log_file = std::fs::OpenOptions::new()
+                        .create(true)
+                        .append(true)
+                        .open(log_path)
+                        .expect("unable to open GA progress log file");
+                    if is_new_log {
+                        writeln!(
+                            log_file,
+                            "generation,meeting_desired_fitness,best_fitness,overall_best_fitness,mean_fitness,std_fitness"
+                        )
+                        .unwrap();
+                    }
+                    // `best_fitness` is only updated with this generation's best further below
+                    let overall_best =
+                        if best_fitness_gen > best_fitness { best_fitness_gen } else { best_fitness };
+                    writeln!(
+                        log_file,
+                        "{},{},{},{},{},{}",
+                        generation, meeting_desired, best_fitness_gen, overall_best, mean_fitness, std_fitness
+                    )
+                    .unwrap();
+
+                    // opt-in full population dump, one line per generation, so a run can be
+                    // resumed or inspected offline
+                    if $log_population {
+                        let mut population_file = std::fs::OpenOptions::new()
+                            .create(true)
+                            .append(true)
+                            .open(format!("{}.population", log_path))
+                            .expect("unable to open GA population log file");
+                        let dump = partial_results
+                            .iter()
+                            .map(|r| format!("{}:{}", r.individual, r.fitness))
+                            .collect::<Vec<_>>()
+                            .join(";");
+                        writeln!(population_file, "{};{}", generation, dump).unwrap();
+                    }
+                }
+
                 // combine the results received
                 all_results.append(&mut partial_results);
             } else {
@@ -347,19 +674,58 @@ macro_rules! explore_ga_distributed_mpi {
                 break;
             }
 
-            // the master do selection, mutation and crossover
+            // the master do elitism, selection, mutation and crossover
             if world.rank() == root_rank {
 
                 // set the population parameters owned by the master
                 // using the ones received from other processors
+                pop_fitness.clear();
                 for i in 0..population_size {
                     let fitness = all_results[(generation as usize -1)*population_size + i].fitness;
                     let individual = all_results[(generation as usize -1)*population_size + i].individual.clone();
-                    pop_fitness[i].push((individual, fitness));
+                    pop_fitness.push((individual, fitness));
                 }
 
-                // compute selection
-                $selection(&mut pop_fitness);
+                // progress: how far this generation's best is above the running average of
+                // recent bests; diversity: fraction of distinct individuals in the population
+                let running_avg_best: f32 = if recent_best_fitness.is_empty() {
+                    best_fitness_gen
+                } else {
+                    recent_best_fitness.iter().sum::<f32>() / recent_best_fitness.len() as f32
+                };
+                let progress = best_fitness_gen - running_avg_best;
+
+                let distinct_individuals: std::collections::HashSet<&String> =
+                    pop_fitness.iter().map(|(individual, _)| individual).collect();
+                let diversity = distinct_individuals.len() as f32 / pop_fitness.len() as f32;
+
+                recent_best_fitness.push_back(best_fitness_gen);
+                if recent_best_fitness.len() > 5 {
+                    recent_best_fitness.pop_front();
+                }
+
+                let adaptive_ctx = GAAdaptiveContext {
+                    generation,
+                    best_fitness: best_fitness_gen,
+                    progress,
+                    diversity,
+                };
+                let mutation_rate = $mutation_rate(&adaptive_ctx);
+                let selection_rate = $selection_rate(&adaptive_ctx);
+
+                // preserve the top `elitism_count` individuals of this generation verbatim,
+                // exempt from mutation/crossover, so the best fitness can never decrease
+                let elitism_count = ($elitism_count as usize).min(pop_fitness.len());
+                pop_fitness.sort_by(|(_, fit_a), (_, fit_b)| fit_b.partial_cmp(fit_a).unwrap());
+                let elites: Vec<String> = pop_fitness
+                    .iter()
+                    .take(elitism_count)
+                    .map(|(individual, _)| individual.clone())
+                    .collect();
+                let mut pop_fitness = pop_fitness.split_off(elitism_count);
+
+                // compute selection on the remaining, non-elite individuals
+                $selection(&mut pop_fitness, selection_rate);
 
                 // check if after selection the population size is too small
                 if pop_fitness.len() <= 1 {
@@ -370,12 +736,21 @@ macro_rules! explore_ga_distributed_mpi {
                 population.clear();
                 // mutate the new population
                 for (individual, _) in pop_fitness.iter_mut() {
-                    $mutation(individual);
+                    $mutation(individual, mutation_rate);
                     population.push(individual.clone());
                 }
 
                 // crossover the new population
                 $crossover(&mut population);
+
+                // the elite individuals survive unchanged into the next generation
+                population.extend(elites);
+
+                // selection/crossover are not guaranteed to preserve the population size,
+                // so re-derive it from the population actually produced; otherwise the next
+                // generation's scatter indexes `population` assuming the old, possibly
+                // larger, size and panics
+                population_size = population.len();
             }
         } // END OF LOOP
         if world.rank() == root_rank{
@@ -383,11 +758,237 @@ macro_rules! explore_ga_distributed_mpi {
             println!("- The best individual is: {:?}", best_individual.unwrap());
         }
 
-        
+
         // return arrays containing all the results of each simulation
-        all_results */
-   
-   
+        all_results
+
+
     }};
 
 }
+
+// macro to perform distribued model exploration using a genetic algorithm based on MPI,
+// following an island model: every rank owns and evolves its own sub-population locally
+// (selection, mutation, crossover) instead of waiting on a per-generation scatter/gather
+// of the whole population. Every `migration_interval` generations each rank sends its
+// `migration_size` best individuals to the next rank (ring topology) and replaces its
+// own worst individuals with the ones received from the previous rank.
+// init_population: function that creates the population, must return an array of individual
+// fitness: function that computes the fitness value, takes a single individual and the schedule, must return an f32
+// selection: function that performs the selection, takes `(population: &mut Vec<(String, f32)>, rate: f64)` --
+//            the same signature `explore_ga_distributed_mpi!` expects, so the built-in
+//            operators in the `selection` module drop into either macro unchanged
+// selection_rate: rate passed through to `$selection` every generation
+// mutation: function that perform the mutation, takes `(individual: &mut String, rate: f64)` --
+//           the same signature `explore_ga_distributed_mpi!` expects
+// mutation_rate: rate passed through to `$mutation` every generation
+// crossover: function that creates the population, takes the entire sub-population as parameter
+// state: state of the simulation representing an individual
+// desired_fitness: desired fitness value
+// generation_num: max number of generations to compute
+// step: number of steps of the single simulation
+// migration_interval: number of generations between two migrations
+// migration_size: number of top individuals migrated at each migration
+#[macro_export]
+macro_rules! explore_ga_distributed_mpi_island {
+    (
+        $init_population:tt,
+        $fitness:tt,
+        $selection:tt,
+        $selection_rate: expr,
+        $mutation:tt,
+        $mutation_rate: expr,
+        $crossover:tt,
+        $state: ty,
+        $desired_fitness: expr,
+        $generation_num: expr,
+        $step: expr,
+        $migration_interval: expr,
+        $migration_size: expr,
+    ) => {{
+        // MPI initialization
+        let universe = mpi::initialize().unwrap();
+        let world = universe.world();
+        let root_rank = 0;
+        let root_process = world.process_at_rank(root_rank);
+        let my_rank = world.rank();
+        let num_procs = world.size() as usize;
+        let next_rank = ((my_rank + 1) % num_procs as i32) as i32;
+        let prev_rank = ((my_rank - 1 + num_procs as i32) % num_procs as i32) as i32;
+
+        if my_rank == root_rank {
+            println!("Running distributed (MPI) GA exploration, island model...");
+        }
+
+        //definition of a dataframe called BufferGA, used both to track local results
+        //and to serialize the migrating individuals
+        build_dataframe_explore!(BufferGA,
+            input {
+                generation: u32
+                index: i32
+                fitness: f32
+            }
+        );
+
+        //implement trait for BufferGA to send/receive with mpi
+        extend_dataframe_explore!(BufferGA,
+            input {
+                generation: u32
+                index: i32
+                fitness: f32
+            }
+        );
+
+        // every rank builds and evolves its own, fully independent, sub-population
+        let mut my_population: Vec<String> = $init_population();
+        let mut generation: u32 = 0;
+        let mut my_best_fitness = 0.;
+        let mut flag = false;
+
+        loop {
+            if $generation_num != 0 && generation == $generation_num {
+                if my_rank == root_rank {
+                    println!("Reached {} generations, exiting...", $generation_num);
+                }
+                break;
+            }
+
+            generation += 1;
+
+            // evaluate the fitness of every individual of the local sub-population
+            let mut my_pop_fitness: Vec<(String, f32)> = Vec::with_capacity(my_population.len());
+            for individual_params in my_population.iter() {
+                let mut individual = <$state>::new_with_parameters(individual_params);
+                let mut schedule: Schedule = Schedule::new();
+                individual.init(&mut schedule);
+                for _ in 0..($step as usize) {
+                    let individual = individual.as_state_mut();
+                    schedule.step(individual);
+                    if individual.end_condition(&mut schedule) {
+                        break;
+                    }
+                }
+                let fitness = $fitness(&mut individual, schedule);
+
+                if fitness >= $desired_fitness {
+                    flag = true;
+                }
+                if fitness > my_best_fitness {
+                    my_best_fitness = fitness;
+                }
+
+                my_pop_fitness.push((individual_params.clone(), fitness));
+            }
+
+            // islands terminate independently (desired fitness reached on just this rank,
+            // or this rank's population collapsed), but every rank still participates in
+            // the migration/gather collectives below; agree on a single stop decision so
+            // every island runs the same number of generations and no rank is left calling
+            // a collective that an already-exited rank will never answer
+            let local_stop = flag || my_pop_fitness.len() <= 1;
+            let mut global_stop = false;
+            world.all_reduce_into(
+                &local_stop,
+                &mut global_stop,
+                &mpi::collective::SystemOperation::logical_or(),
+            );
+            if global_stop {
+                if my_rank == root_rank {
+                    println!("Stopping criteria met on generation {}, exiting...", generation);
+                }
+                break;
+            }
+
+            // every `migration_interval` generations, ring-exchange the best individuals
+            if $migration_interval != 0 && generation % ($migration_interval as u32) == 0 && num_procs > 1 {
+                // rank the local sub-population by fitness, best first
+                my_pop_fitness
+                    .sort_by(|(_, fit_a), (_, fit_b)| fit_b.partial_cmp(fit_a).unwrap());
+
+                // every island evolves its own sub-population independently, so sizes can have
+                // diverged; agree on a single migration count for the whole ring so every
+                // sender's loop matches its receiver's loop one for one
+                let local_wanted = ($migration_size as usize).min(my_pop_fitness.len()) as u64;
+                let mut migration_size_u64 = 0u64;
+                world.all_reduce_into(
+                    &local_wanted,
+                    &mut migration_size_u64,
+                    &mpi::collective::SystemOperation::min(),
+                );
+                let migration_size = migration_size_u64 as usize;
+
+                let mut immigrants: Vec<(String, f32)> = Vec::with_capacity(migration_size);
+
+                let send_migrants = || {
+                    for (individual, fit) in my_pop_fitness.iter().take(migration_size) {
+                        let int_type = u8::equivalent_datatype().dup();
+                        let mut bytes = individual.clone().into_bytes();
+                        let buffer_to_send = unsafe {
+                            DynBufferMut::from_raw(&mut bytes, bytes.len() as i32, int_type.as_ref())
+                        };
+                        world.process_at_rank(next_rank).send(&buffer_to_send);
+                        world.process_at_rank(next_rank).send(fit);
+                    }
+                };
+                let mut receive_migrants = |immigrants: &mut Vec<(String, f32)>| {
+                    for _ in 0..migration_size {
+                        let (bytes, _) = world.process_at_rank(prev_rank).receive_vec::<u8>();
+                        let (fit, _) = world.process_at_rank(prev_rank).receive::<f32>();
+                        let individual =
+                            String::from_utf8(bytes).expect("invalid individual encoding");
+                        immigrants.push((individual, fit));
+                    }
+                };
+
+                // sending to `next_rank` and receiving from `prev_rank` in the same order on
+                // every rank deadlocks as soon as a migrating batch no longer fits MPI's eager
+                // buffer, since every rank would then be blocked sending before anyone gets to
+                // receive; even/odd ranks swap the order instead, so each ring edge always has
+                // one side receiving while the other sends
+                if my_rank % 2 == 0 {
+                    send_migrants();
+                    receive_migrants(&mut immigrants);
+                } else {
+                    receive_migrants(&mut immigrants);
+                    send_migrants();
+                }
+
+                // the immigrants replace the worst local individuals
+                let pop_len = my_pop_fitness.len();
+                for (i, immigrant) in immigrants.into_iter().enumerate() {
+                    my_pop_fitness[pop_len - 1 - i] = immigrant;
+                }
+            }
+
+            // local selection, mutation and crossover, fully independent from the other ranks
+            $selection(&mut my_pop_fitness, $selection_rate);
+
+            my_population.clear();
+            for (individual, _) in my_pop_fitness.iter_mut() {
+                $mutation(individual, $mutation_rate);
+                my_population.push(individual.clone());
+            }
+
+            $crossover(&mut my_population);
+        } // END OF LOOP
+
+        // the root rank aggregates the global best fitness found across all islands
+        let mut all_best_fitness: Vec<f32> = vec![0.; num_procs];
+        if my_rank == root_rank {
+            root_process.gather_into_root(&my_best_fitness, &mut all_best_fitness[..]);
+        } else {
+            root_process.gather_into(&my_best_fitness);
+        }
+
+        if my_rank == root_rank {
+            let global_best = all_best_fitness
+                .iter()
+                .cloned()
+                .fold(0_f32, |acc, f| if f > acc { f } else { acc });
+            println!("Overall best fitness across all islands is {}", global_best);
+            global_best
+        } else {
+            my_best_fitness
+        }
+    }};
+}